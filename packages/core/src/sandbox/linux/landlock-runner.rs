@@ -5,8 +5,8 @@
 
 use enumflags2::BitFlags;
 use landlock::{
-    Access, AccessFs, CompatLevel, Compatible, LandlockStatus, PathBeneath, PathFd, Ruleset,
-    RulesetAttr, RulesetCreated, RulesetCreatedAttr, RulesetStatus, ABI,
+    Access, AccessFs, AccessNet, CompatLevel, Compatible, LandlockStatus, NetPort, PathBeneath,
+    PathFd, Ruleset, RulesetAttr, RulesetCreated, RulesetCreatedAttr, RulesetStatus, ABI,
 };
 use libc::{c_char, execvp};
 use std::env;
@@ -26,6 +26,8 @@ fn main() {
 
     let mut rw_paths: Vec<OsString> = Vec::new();
     let mut ro_paths: Vec<OsString> = Vec::new();
+    let mut allow_bind_ports: Vec<u16> = Vec::new();
+    let mut allow_connect_ports: Vec<u16> = Vec::new();
 
     while !args.is_empty() {
         if args[0] == "--" {
@@ -36,10 +38,12 @@ fn main() {
             usage_and_exit();
         }
         let flag = args.remove(0);
-        let path = args.remove(0);
+        let value = args.remove(0);
         match flag.as_os_str().to_str() {
-            Some("--rw") => rw_paths.push(path),
-            Some("--ro") => ro_paths.push(path),
+            Some("--rw") => rw_paths.push(value),
+            Some("--ro") => ro_paths.push(value),
+            Some("--allow-bind") => allow_bind_ports.push(parse_port(&value)),
+            Some("--allow-connect") => allow_connect_ports.push(parse_port(&value)),
             _ => usage_and_exit(),
         }
     }
@@ -53,12 +57,16 @@ fn main() {
     let abi = ABI::V6;
     let ro_access = AccessFs::from_read(abi);
     let rw_access = AccessFs::from_all(abi);
+    // With no --allow-bind/--allow-connect flags, handling BindTcp/ConnectTcp
+    // without ever adding a NetPort rule denies all TCP bind/connect.
+    let net_access = AccessNet::BindTcp | AccessNet::ConnectTcp;
 
-    let handled = ro_access | rw_access;
-
+    // `AccessFs` and `AccessNet` are distinct access types, so each needs its
+    // own `handle_access` call rather than being combined into one bitmask.
     let mut ruleset = match Ruleset::default()
         .set_compatibility(CompatLevel::BestEffort)
-        .handle_access(handled)
+        .handle_access(ro_access | rw_access)
+        .and_then(|ruleset| ruleset.handle_access(net_access))
     {
         Ok(ruleset) => match ruleset.create() {
             Ok(created) => created,
@@ -73,6 +81,13 @@ fn main() {
         }
     };
 
+    for port in allow_bind_ports {
+        ruleset = add_net_port_rule(ruleset, port, AccessNet::BindTcp);
+    }
+    for port in allow_connect_ports {
+        ruleset = add_net_port_rule(ruleset, port, AccessNet::ConnectTcp);
+    }
+
     for p in ro_paths {
         let (path, access) = normalize_path_and_access(p, false, abi);
         ruleset = add_path_rule(ruleset, path, access);
@@ -162,6 +177,30 @@ fn add_path_rule(
     }
 }
 
+fn add_net_port_rule(
+    ruleset: RulesetCreated,
+    port: u16,
+    access: AccessNet,
+) -> RulesetCreated {
+    let rule = NetPort::new(port, access);
+    match ruleset.add_rule(rule) {
+        Ok(ruleset) => ruleset,
+        Err(err) => {
+            eprintln!("[landlock-runner] landlock_add_rule failed for port {port}: {err}");
+            exit(111);
+        }
+    }
+}
+
+fn parse_port(raw: &OsString) -> u16 {
+    raw.to_str()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| {
+            eprintln!("[landlock-runner] Invalid port: {:?}", raw);
+            exit(64);
+        })
+}
+
 fn normalize_path_and_access(
     path: OsString,
     write: bool,
@@ -210,7 +249,7 @@ fn last_errno() -> i32 {
 
 fn usage_and_exit() -> ! {
     eprintln!(
-        "Usage: landlock-runner [--rw PATH]... [--ro PATH]... -- <command> [args...]"
+        "Usage: landlock-runner [--rw PATH]... [--ro PATH]... [--allow-bind PORT]... [--allow-connect PORT]... -- <command> [args...]"
     );
     exit(64);
 }
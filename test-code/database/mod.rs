@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+/// Database connection configuration
+#[derive(Debug, Clone)]
+pub struct DatabaseConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: String,
+    pub database: String,
+}
+
+/// Custom error type for database operations
+#[derive(Debug)]
+pub enum DatabaseError {
+    ConnectionFailed(String),
+    QueryFailed(String),
+    InvalidData(String),
+    NotFound(String),
+    Timeout(String),
+    /// An error surfaced by a backend's driver, e.g. the host-provided
+    /// adapter on the `wasm` backend.
+    Backend(String),
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DatabaseError::ConnectionFailed(msg) => write!(f, "Connection failed: {}", msg),
+            DatabaseError::QueryFailed(msg) => write!(f, "Query failed: {}", msg),
+            DatabaseError::InvalidData(msg) => write!(f, "Invalid data: {}", msg),
+            DatabaseError::NotFound(msg) => write!(f, "Not found: {}", msg),
+            DatabaseError::Timeout(msg) => write!(f, "Timed out: {}", msg),
+            DatabaseError::Backend(msg) => write!(f, "Backend error: {}", msg),
+        }
+    }
+}
+
+impl Error for DatabaseError {}
+
+/// Represents a database record
+///
+/// Deriving `Serialize`/`Deserialize` here requires the `chrono` dependency
+/// to be pulled in with its `serde` feature enabled (`chrono = { version =
+/// "...", features = ["serde"] }`); without it, `DateTime<Utc>` below has no
+/// serde impls and this derive fails to compile.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Record {
+    pub id: u64,
+    pub data: HashMap<String, String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A single raw row returned by a query, indexable by column position.
+#[derive(Debug, Clone)]
+pub struct Row {
+    columns: Vec<String>,
+}
+
+impl Row {
+    /// Build a row from its raw column values, in column order.
+    pub fn new(columns: Vec<String>) -> Self {
+        Row { columns }
+    }
+
+    /// Decode the column at `index` as `T`.
+    pub fn get<T: FromColumn>(&self, index: usize) -> Result<T, DatabaseError> {
+        let raw = self
+            .columns
+            .get(index)
+            .ok_or_else(|| DatabaseError::InvalidData(format!("column index {index} out of range")))?;
+        T::from_column(raw)
+    }
+}
+
+/// Decodes a single column value, by index, into a Rust type.
+pub trait FromColumn: Sized {
+    fn from_column(raw: &str) -> Result<Self, DatabaseError>;
+}
+
+impl FromColumn for String {
+    fn from_column(raw: &str) -> Result<Self, DatabaseError> {
+        Ok(raw.to_string())
+    }
+}
+
+macro_rules! impl_from_column_for_parsed {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl FromColumn for $t {
+                fn from_column(raw: &str) -> Result<Self, DatabaseError> {
+                    raw.parse().map_err(|_| {
+                        DatabaseError::InvalidData(format!(
+                            "expected {}, got {raw:?}",
+                            stringify!($t)
+                        ))
+                    })
+                }
+            }
+        )+
+    };
+}
+
+impl_from_column_for_parsed!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, bool);
+
+/// Maps a `Row` into a typed value, decoding each column by position.
+///
+/// Blanket implementations exist for tuples of up to 12 `FromColumn`
+/// elements; implement this directly for a struct to opt into
+/// `DatabaseConnection::query_as` by column order.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError>;
+}
+
+macro_rules! impl_from_row_for_tuple {
+    ($($idx:tt => $t:ident),+) => {
+        impl<$($t: FromColumn),+> FromRow for ($($t,)+) {
+            fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+                Ok(($(row.get::<$t>($idx)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(0 => A);
+impl_from_row_for_tuple!(0 => A, 1 => B);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J, 10 => K);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J, 10 => K, 11 => L);
+
+// `DatabaseConnection` is split into backend-specific submodules selected by
+// feature flag so the crate can target `wasm32-unknown-unknown`: the
+// `native` backend is the std/tokio-based implementation used everywhere
+// else, while the `wasm` backend routes I/O through a host-injected driver
+// adapter instead of opening sockets itself.
+#[cfg(all(feature = "native", feature = "wasm"))]
+compile_error!("features `native` and `wasm` are mutually exclusive: each provides its own `DatabaseConnection`, so enabling both produces a conflicting re-export rather than a working build");
+
+#[cfg(feature = "native")]
+mod native;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "native")]
+pub use native::{DatabaseManager, InnerConn, PooledConnection};
+#[cfg(feature = "native")]
+pub use native::DatabaseConnection;
+
+#[cfg(feature = "wasm")]
+pub use wasm::{DatabaseConnection, DriverAdapter};
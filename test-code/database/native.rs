@@ -0,0 +1,443 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::timeout;
+
+use super::{DatabaseConfig, DatabaseError, FromRow, Record, Row};
+
+/// Database connection manager backed by a bounded pool of idle connections.
+///
+/// Concurrency is capped by `semaphore`: acquiring a connection takes a
+/// permit, and the permit is released when the returned `PooledConnection`
+/// is dropped. This gives callers real backpressure instead of an unbounded
+/// `Vec` of connections.
+pub struct DatabaseManager {
+    config: DatabaseConfig,
+    idle_connections: Arc<Mutex<VecDeque<DatabaseConnection>>>,
+    semaphore: Arc<Semaphore>,
+    max_connections: usize,
+    acquire_timeout: Duration,
+    max_idle: Duration,
+}
+
+impl DatabaseManager {
+    pub fn new(config: DatabaseConfig) -> Self {
+        Self::with_limits(config, 10, Duration::from_secs(5), Duration::from_secs(300))
+    }
+
+    /// Construct a manager with an explicit pool size, acquire timeout, and
+    /// maximum idle duration before a pooled connection is discarded and
+    /// rebuilt.
+    pub fn with_limits(
+        config: DatabaseConfig,
+        max_connections: usize,
+        acquire_timeout: Duration,
+        max_idle: Duration,
+    ) -> Self {
+        DatabaseManager {
+            config,
+            idle_connections: Arc::new(Mutex::new(VecDeque::new())),
+            semaphore: Arc::new(Semaphore::new(max_connections)),
+            max_connections,
+            acquire_timeout,
+            max_idle,
+        }
+    }
+
+    pub fn max_connections(&self) -> usize {
+        self.max_connections
+    }
+
+    /// Acquire a pooled connection, waiting for a free permit up to
+    /// `acquire_timeout`. Idle connections that are inactive or have sat
+    /// unused for longer than `max_idle` are discarded rather than reused.
+    pub async fn acquire(&self) -> Result<PooledConnection, DatabaseError> {
+        let permit = timeout(self.acquire_timeout, Arc::clone(&self.semaphore).acquire_owned())
+            .await
+            .map_err(|_| {
+                DatabaseError::Timeout("timed out waiting for an available connection".to_string())
+            })?
+            .expect("connection semaphore should never be closed");
+
+        let connection = loop {
+            let candidate = {
+                let mut idle = self.idle_connections.lock().unwrap();
+                idle.pop_front()
+            };
+            match candidate {
+                Some(conn) if conn.is_active && !conn.is_expired(self.max_idle) => break conn,
+                Some(_) => continue,
+                None => break DatabaseConnection::new(&self.config).await?,
+            }
+        };
+
+        Ok(PooledConnection {
+            connection: Some(connection),
+            idle_connections: Arc::clone(&self.idle_connections),
+            _permit: permit,
+        })
+    }
+
+    /// Execute a query and return results
+    pub async fn execute_query(
+        &self,
+        query: &str,
+        params: &[&str],
+    ) -> Result<Vec<Record>, DatabaseError> {
+        let connection = self.acquire().await?;
+        connection.execute_query(query, params).await
+    }
+
+    /// Run a query and map each row into `T` via `FromRow`. See
+    /// `DatabaseConnection::query_as`.
+    pub async fn query_as<T: FromRow>(
+        &self,
+        query: &str,
+        params: &[&str],
+    ) -> Result<Vec<T>, DatabaseError> {
+        let connection = self.acquire().await?;
+        connection.query_as(query, params).await
+    }
+
+    /// Insert a new record
+    pub async fn insert_record(
+        &self,
+        table: &str,
+        data: HashMap<String, String>,
+    ) -> Result<u64, DatabaseError> {
+        let connection = self.acquire().await?;
+        connection.insert_record(table, data).await
+    }
+
+    /// Update an existing record
+    pub async fn update_record(
+        &self,
+        table: &str,
+        id: u64,
+        data: HashMap<String, String>,
+    ) -> Result<bool, DatabaseError> {
+        let connection = self.acquire().await?;
+        connection.update_record(table, id, data).await
+    }
+
+    /// Delete a record
+    pub async fn delete_record(&self, table: &str, id: u64) -> Result<bool, DatabaseError> {
+        let connection = self.acquire().await?;
+        connection.delete_record(table, id).await
+    }
+
+    /// Run a closure against a checked-out connection's underlying driver
+    /// handle on a blocking thread. See `DatabaseConnection::run` for
+    /// details; this is a convenience that acquires a connection first.
+    pub async fn run<F, R>(&self, f: F) -> Result<R, DatabaseError>
+    where
+        F: FnOnce(&mut InnerConn) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let connection = self.acquire().await?;
+        Ok(connection.run(f).await)
+    }
+}
+
+/// A connection checked out of a `DatabaseManager`'s pool.
+///
+/// Dereferences to the underlying `DatabaseConnection`. On drop, the
+/// connection is returned to the idle deque and the semaphore permit is
+/// released, making the slot available to the next caller.
+pub struct PooledConnection {
+    connection: Option<DatabaseConnection>,
+    idle_connections: Arc<Mutex<VecDeque<DatabaseConnection>>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl std::ops::Deref for PooledConnection {
+    type Target = DatabaseConnection;
+
+    fn deref(&self) -> &DatabaseConnection {
+        self.connection
+            .as_ref()
+            .expect("connection is only taken on drop")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(mut connection) = self.connection.take() {
+            connection.last_used = chrono::Utc::now();
+            if let Ok(mut idle) = self.idle_connections.lock() {
+                idle.push_back(connection);
+            }
+        }
+    }
+}
+
+/// The underlying (synchronous) driver handle for a connection.
+///
+/// `DatabaseConnection::run` hands this to closures dispatched via
+/// `spawn_blocking`, so callers can run arbitrary multi-statement
+/// transactions against the checked-out connection instead of being
+/// limited to the fixed `execute_query`/`insert_record`/`update_record`
+/// helpers.
+#[derive(Debug)]
+pub struct InnerConn {
+    id: String,
+}
+
+/// Individual database connection
+#[derive(Debug, Clone)]
+pub struct DatabaseConnection {
+    pub id: String,
+    pub is_active: bool,
+    pub last_used: chrono::DateTime<chrono::Utc>,
+    inner: Arc<Mutex<InnerConn>>,
+}
+
+impl DatabaseConnection {
+    pub async fn new(config: &DatabaseConfig) -> Result<Self, DatabaseError> {
+        // Simulate connection establishment
+        let id = format!("conn_{}", uuid::Uuid::new_v4());
+        let connection = DatabaseConnection {
+            inner: Arc::new(Mutex::new(InnerConn { id: id.clone() })),
+            id,
+            is_active: true,
+            last_used: chrono::Utc::now(),
+        };
+
+        // Simulate connection test
+        if !Self::test_connection(config).await {
+            return Err(DatabaseError::ConnectionFailed(
+                "Failed to establish connection".to_string(),
+            ));
+        }
+
+        Ok(connection)
+    }
+
+    async fn test_connection(config: &DatabaseConfig) -> bool {
+        // Simulate connection test
+        config.host.len() > 0 && config.port > 0
+    }
+
+    /// Whether this connection has been idle for longer than `max_idle` and
+    /// should be discarded instead of reused.
+    fn is_expired(&self, max_idle: Duration) -> bool {
+        let max_idle = match chrono::Duration::from_std(max_idle) {
+            Ok(max_idle) => max_idle,
+            // Too large for chrono to represent: no connection has been idle that long.
+            Err(_) => return false,
+        };
+        chrono::Utc::now() - self.last_used > max_idle
+    }
+
+    pub async fn execute_query(
+        &self,
+        query: &str,
+        params: &[&str],
+    ) -> Result<Vec<Record>, DatabaseError> {
+        // Simulate query execution
+        if query.is_empty() {
+            return Err(DatabaseError::QueryFailed("Empty query".to_string()));
+        }
+
+        let query = query.to_string();
+        let params_count = params.len();
+        Ok(self
+            .run(move |_inner| {
+                // Return mock data
+                let mut record = Record {
+                    id: 1,
+                    data: HashMap::new(),
+                    created_at: chrono::Utc::now(),
+                    updated_at: chrono::Utc::now(),
+                };
+                record.data.insert("query".to_string(), query);
+                record.data.insert("params_count".to_string(), params_count.to_string());
+
+                vec![record]
+            })
+            .await)
+    }
+
+    /// Run a query and map each row into `T` via `FromRow`, e.g.
+    /// `query_as::<(u64, String)>("SELECT id, name FROM users", &[])`
+    /// instead of re-parsing a stringly-typed `Record`.
+    pub async fn query_as<T: FromRow>(
+        &self,
+        query: &str,
+        params: &[&str],
+    ) -> Result<Vec<T>, DatabaseError> {
+        let rows = self.query_rows(query, params).await?;
+        rows.iter().map(T::from_row).collect()
+    }
+
+    async fn query_rows(&self, query: &str, params: &[&str]) -> Result<Vec<Row>, DatabaseError> {
+        if query.is_empty() {
+            return Err(DatabaseError::QueryFailed("Empty query".to_string()));
+        }
+
+        let query = query.to_string();
+        let params_count = params.len();
+        Ok(self
+            .run(move |_inner| {
+                // Simulate a single returned row.
+                vec![Row {
+                    columns: vec![query, params_count.to_string()],
+                }]
+            })
+            .await)
+    }
+
+    pub async fn insert_record(
+        &self,
+        table: &str,
+        data: HashMap<String, String>,
+    ) -> Result<u64, DatabaseError> {
+        // Simulate record insertion
+        if table.is_empty() {
+            return Err(DatabaseError::InvalidData("Empty table name".to_string()));
+        }
+        let _ = data;
+
+        Ok(self.run(|_inner| chrono::Utc::now().timestamp() as u64).await)
+    }
+
+    pub async fn update_record(
+        &self,
+        table: &str,
+        id: u64,
+        data: HashMap<String, String>,
+    ) -> Result<bool, DatabaseError> {
+        // Simulate record update
+        if table.is_empty() || id == 0 {
+            return Err(DatabaseError::InvalidData("Invalid table or ID".to_string()));
+        }
+        let _ = data;
+
+        Ok(self.run(|_inner| true).await)
+    }
+
+    pub async fn delete_record(&self, table: &str, id: u64) -> Result<bool, DatabaseError> {
+        // Simulate record deletion
+        if table.is_empty() || id == 0 {
+            return Err(DatabaseError::InvalidData("Invalid table or ID".to_string()));
+        }
+
+        Ok(self.run(|_inner| true).await)
+    }
+
+    /// Run a closure against the underlying driver handle on a blocking
+    /// thread via `tokio::task::spawn_blocking`, awaiting the join and
+    /// resuming the panic if the closure panicked. This is the standard way
+    /// to integrate a synchronous driver into an async reactor without
+    /// blocking it.
+    pub async fn run<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut InnerConn) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || {
+            let mut guard = inner.lock().unwrap();
+            f(&mut guard)
+        })
+        .await
+        .unwrap_or_else(|join_err| std::panic::resume_unwind(join_err.into_panic()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_database_connection() {
+        let config = DatabaseConfig {
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "test_user".to_string(),
+            password: "test_pass".to_string(),
+            database: "test_db".to_string(),
+        };
+
+        let connection = DatabaseConnection::new(&config).await;
+        assert!(connection.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_query_execution() {
+        let config = DatabaseConfig {
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "test_user".to_string(),
+            password: "test_pass".to_string(),
+            database: "test_db".to_string(),
+        };
+
+        let connection = DatabaseConnection::new(&config).await.unwrap();
+        let result = connection.execute_query("SELECT * FROM users", &[]).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_enforces_max_connections() {
+        let config = DatabaseConfig {
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "test_user".to_string(),
+            password: "test_pass".to_string(),
+            database: "test_db".to_string(),
+        };
+
+        let manager =
+            DatabaseManager::with_limits(config, 1, Duration::from_millis(50), Duration::from_secs(60));
+
+        let first = manager.acquire().await.unwrap();
+        let second = manager.acquire().await;
+        assert!(matches!(second, Err(DatabaseError::Timeout(_))));
+
+        drop(first);
+        assert!(manager.acquire().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_query_as_maps_typed_tuple() {
+        let config = DatabaseConfig {
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "test_user".to_string(),
+            password: "test_pass".to_string(),
+            database: "test_db".to_string(),
+        };
+
+        let connection = DatabaseConnection::new(&config).await.unwrap();
+        let rows: Vec<(String, u64)> = connection
+            .query_as("SELECT * FROM users", &["1"])
+            .await
+            .unwrap();
+        assert_eq!(rows, vec![("SELECT * FROM users".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_connection_is_recycled_after_drop() {
+        let config = DatabaseConfig {
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "test_user".to_string(),
+            password: "test_pass".to_string(),
+            database: "test_db".to_string(),
+        };
+
+        let manager =
+            DatabaseManager::with_limits(config, 1, Duration::from_millis(50), Duration::from_secs(60));
+
+        let id = {
+            let conn = manager.acquire().await.unwrap();
+            conn.id.clone()
+        };
+
+        let recycled = manager.acquire().await.unwrap();
+        assert_eq!(recycled.id, id);
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::{DatabaseError, FromRow, Record, Row};
+
+/// Host-provided hook that performs the actual I/O for a wasm-backed
+/// connection. On `wasm32-unknown-unknown` the crate never opens a socket
+/// itself; instead the embedding JS (or other host runtime) supplies an
+/// implementation of this trait, e.g. one that proxies to a driver running
+/// outside the sandboxed module.
+#[async_trait::async_trait(?Send)]
+pub trait DriverAdapter {
+    async fn execute_query(&self, query: &str, params: &[&str]) -> Result<Vec<Row>, String>;
+    async fn insert_record(&self, table: &str, data: Vec<(String, String)>) -> Result<u64, String>;
+    async fn update_record(
+        &self,
+        table: &str,
+        id: u64,
+        data: Vec<(String, String)>,
+    ) -> Result<bool, String>;
+    async fn delete_record(&self, table: &str, id: u64) -> Result<bool, String>;
+}
+
+/// A connection whose I/O is routed through an injected `DriverAdapter`
+/// rather than a native socket, so the same query API can be used from a
+/// browser/edge runtime where the connection is provided externally.
+#[derive(Clone)]
+pub struct DatabaseConnection {
+    adapter: Arc<dyn DriverAdapter>,
+}
+
+impl DatabaseConnection {
+    pub fn new(adapter: Arc<dyn DriverAdapter>) -> Self {
+        DatabaseConnection { adapter }
+    }
+
+    pub async fn execute_query(
+        &self,
+        query: &str,
+        params: &[&str],
+    ) -> Result<Vec<Record>, DatabaseError> {
+        let rows = self
+            .adapter
+            .execute_query(query, params)
+            .await
+            .map_err(DatabaseError::Backend)?;
+
+        rows.iter()
+            .map(|row| {
+                let mut data = HashMap::new();
+                data.insert("query".to_string(), row.get::<String>(0)?);
+                data.insert("params_count".to_string(), row.get::<String>(1)?);
+                Ok(Record {
+                    id: 0,
+                    data,
+                    created_at: chrono::Utc::now(),
+                    updated_at: chrono::Utc::now(),
+                })
+            })
+            .collect()
+    }
+
+    /// Run a query and map each row into `T` via `FromRow`. See
+    /// `FromRow`/the native backend's `query_as` for details.
+    pub async fn query_as<T: FromRow>(
+        &self,
+        query: &str,
+        params: &[&str],
+    ) -> Result<Vec<T>, DatabaseError> {
+        let rows = self
+            .adapter
+            .execute_query(query, params)
+            .await
+            .map_err(DatabaseError::Backend)?;
+        rows.iter().map(T::from_row).collect()
+    }
+
+    pub async fn insert_record(
+        &self,
+        table: &str,
+        data: HashMap<String, String>,
+    ) -> Result<u64, DatabaseError> {
+        self.adapter
+            .insert_record(table, data.into_iter().collect())
+            .await
+            .map_err(DatabaseError::Backend)
+    }
+
+    pub async fn update_record(
+        &self,
+        table: &str,
+        id: u64,
+        data: HashMap<String, String>,
+    ) -> Result<bool, DatabaseError> {
+        self.adapter
+            .update_record(table, id, data.into_iter().collect())
+            .await
+            .map_err(DatabaseError::Backend)
+    }
+
+    pub async fn delete_record(&self, table: &str, id: u64) -> Result<bool, DatabaseError> {
+        self.adapter
+            .delete_record(table, id)
+            .await
+            .map_err(DatabaseError::Backend)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A `DriverAdapter` standing in for the host-supplied hook, so the
+    /// wasm backend can be exercised without a real JS runtime.
+    struct MockAdapter;
+
+    #[async_trait::async_trait(?Send)]
+    impl DriverAdapter for MockAdapter {
+        async fn execute_query(&self, query: &str, params: &[&str]) -> Result<Vec<Row>, String> {
+            Ok(vec![Row {
+                columns: vec![query.to_string(), params.len().to_string()],
+            }])
+        }
+
+        async fn insert_record(&self, _table: &str, _data: Vec<(String, String)>) -> Result<u64, String> {
+            Ok(1)
+        }
+
+        async fn update_record(
+            &self,
+            _table: &str,
+            _id: u64,
+            _data: Vec<(String, String)>,
+        ) -> Result<bool, String> {
+            Ok(true)
+        }
+
+        async fn delete_record(&self, _table: &str, _id: u64) -> Result<bool, String> {
+            Ok(true)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_query_as_via_mock_adapter() {
+        let connection = DatabaseConnection::new(Arc::new(MockAdapter));
+        let rows: Vec<(String, u64)> = connection
+            .query_as("SELECT * FROM users", &["1"])
+            .await
+            .unwrap();
+        assert_eq!(rows, vec![("SELECT * FROM users".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_query_maps_host_error() {
+        struct FailingAdapter;
+
+        #[async_trait::async_trait(?Send)]
+        impl DriverAdapter for FailingAdapter {
+            async fn execute_query(&self, _query: &str, _params: &[&str]) -> Result<Vec<Row>, String> {
+                Err("host driver unavailable".to_string())
+            }
+            async fn insert_record(&self, _table: &str, _data: Vec<(String, String)>) -> Result<u64, String> {
+                unreachable!()
+            }
+            async fn update_record(
+                &self,
+                _table: &str,
+                _id: u64,
+                _data: Vec<(String, String)>,
+            ) -> Result<bool, String> {
+                unreachable!()
+            }
+            async fn delete_record(&self, _table: &str, _id: u64) -> Result<bool, String> {
+                unreachable!()
+            }
+        }
+
+        let connection = DatabaseConnection::new(Arc::new(FailingAdapter));
+        let result = connection.execute_query("SELECT * FROM users", &[]).await;
+        assert!(matches!(result, Err(DatabaseError::Backend(_))));
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use futures::{SinkExt, StreamExt};
+use tokio::net::UnixStream;
+use tokio_util::codec::{Framed, LengthDelimitedCodec};
+
+use crate::database_protocol::{Request, Response, DEFAULT_SOCKET_PATH};
+use crate::database::{DatabaseError, Record};
+
+/// Thin client for a `DatabaseServer` daemon over its Unix domain socket.
+/// Unlike `DatabaseManager`, this holds no connection pool of its own — it
+/// just forwards requests to the daemon, which owns the real pool.
+pub struct DatabaseClient {
+    framed: Framed<UnixStream, LengthDelimitedCodec>,
+}
+
+impl DatabaseClient {
+    pub async fn connect() -> std::io::Result<Self> {
+        Self::connect_to(DEFAULT_SOCKET_PATH).await
+    }
+
+    pub async fn connect_to(socket_path: &str) -> std::io::Result<Self> {
+        let stream = UnixStream::connect(socket_path).await?;
+        Ok(DatabaseClient {
+            framed: Framed::new(stream, LengthDelimitedCodec::new()),
+        })
+    }
+
+    pub async fn execute_query(
+        &mut self,
+        query: &str,
+        params: &[&str],
+    ) -> Result<Vec<Record>, DatabaseError> {
+        let request = Request::ExecuteQuery {
+            query: query.to_string(),
+            params: params.iter().map(|p| p.to_string()).collect(),
+        };
+        match self.send(request).await? {
+            Response::Records(records) => Ok(records),
+            Response::Err(err) => Err(err.into()),
+            _ => Err(DatabaseError::QueryFailed(
+                "unexpected response from database server".to_string(),
+            )),
+        }
+    }
+
+    pub async fn insert_record(
+        &mut self,
+        table: &str,
+        data: HashMap<String, String>,
+    ) -> Result<u64, DatabaseError> {
+        let request = Request::Insert {
+            table: table.to_string(),
+            data,
+        };
+        match self.send(request).await? {
+            Response::RowsAffected(id) => Ok(id),
+            Response::Err(err) => Err(err.into()),
+            _ => Err(DatabaseError::QueryFailed(
+                "unexpected response from database server".to_string(),
+            )),
+        }
+    }
+
+    pub async fn update_record(
+        &mut self,
+        table: &str,
+        id: u64,
+        data: HashMap<String, String>,
+    ) -> Result<bool, DatabaseError> {
+        let request = Request::Update {
+            table: table.to_string(),
+            id,
+            data,
+        };
+        match self.send(request).await? {
+            Response::Ok(ok) => Ok(ok),
+            Response::Err(err) => Err(err.into()),
+            _ => Err(DatabaseError::QueryFailed(
+                "unexpected response from database server".to_string(),
+            )),
+        }
+    }
+
+    pub async fn delete_record(&mut self, table: &str, id: u64) -> Result<bool, DatabaseError> {
+        let request = Request::Delete {
+            table: table.to_string(),
+            id,
+        };
+        match self.send(request).await? {
+            Response::Ok(ok) => Ok(ok),
+            Response::Err(err) => Err(err.into()),
+            _ => Err(DatabaseError::QueryFailed(
+                "unexpected response from database server".to_string(),
+            )),
+        }
+    }
+
+    async fn send(&mut self, request: Request) -> Result<Response, DatabaseError> {
+        let encoded = serde_json::to_vec(&request).expect("Request is always serializable");
+        self.framed
+            .send(encoded.into())
+            .await
+            .map_err(|err| DatabaseError::ConnectionFailed(err.to_string()))?;
+        let frame = self
+            .framed
+            .next()
+            .await
+            .ok_or_else(|| DatabaseError::ConnectionFailed("server closed the connection".to_string()))?
+            .map_err(|err| DatabaseError::ConnectionFailed(err.to_string()))?;
+        serde_json::from_slice(&frame)
+            .map_err(|err| DatabaseError::InvalidData(format!("malformed response: {err}")))
+    }
+}
+
+/// Minimal CLI entry point: `database-client [--json] <query>`, intended for
+/// scripting against a running `DatabaseServer`.
+pub async fn run_cli(args: &[String]) -> std::io::Result<()> {
+    let json_output = args.iter().any(|a| a == "--json");
+    let query = args.iter().find(|a| !a.starts_with("--")).cloned().unwrap_or_default();
+
+    let mut client = DatabaseClient::connect().await?;
+    match client.execute_query(&query, &[]).await {
+        Ok(records) if json_output => {
+            println!("{}", serde_json::to_string(&records).unwrap());
+        }
+        Ok(records) => {
+            for record in records {
+                println!("{:?}", record);
+            }
+        }
+        Err(err) => eprintln!("query failed: {err}"),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::database::{DatabaseError, Record};
+
+/// Default path for the daemon's Unix domain socket.
+pub const DEFAULT_SOCKET_PATH: &str = "/tmp/gemini-db.sock";
+
+/// A request sent from a `DatabaseClient` to the `DatabaseServer` daemon,
+/// one for each of `DatabaseManager`'s `execute_query`, `insert_record`,
+/// `update_record` and `delete_record`.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Request {
+    ExecuteQuery { query: String, params: Vec<String> },
+    Insert { table: String, data: HashMap<String, String> },
+    Update { table: String, id: u64, data: HashMap<String, String> },
+    Delete { table: String, id: u64 },
+}
+
+/// The daemon's response to a `Request`.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Response {
+    Records(Vec<Record>),
+    RowsAffected(u64),
+    Ok(bool),
+    Err(SerializableError),
+}
+
+/// `DatabaseError` carries only `String` payloads already, but it isn't
+/// `Serialize`/`Deserialize` itself, so the wire format uses this
+/// structurally identical mirror instead of depending on the error type's
+/// internals.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum SerializableError {
+    ConnectionFailed(String),
+    QueryFailed(String),
+    InvalidData(String),
+    NotFound(String),
+    Timeout(String),
+    Backend(String),
+}
+
+impl From<DatabaseError> for SerializableError {
+    fn from(err: DatabaseError) -> Self {
+        match err {
+            DatabaseError::ConnectionFailed(msg) => SerializableError::ConnectionFailed(msg),
+            DatabaseError::QueryFailed(msg) => SerializableError::QueryFailed(msg),
+            DatabaseError::InvalidData(msg) => SerializableError::InvalidData(msg),
+            DatabaseError::NotFound(msg) => SerializableError::NotFound(msg),
+            DatabaseError::Timeout(msg) => SerializableError::Timeout(msg),
+            DatabaseError::Backend(msg) => SerializableError::Backend(msg),
+        }
+    }
+}
+
+impl From<SerializableError> for DatabaseError {
+    fn from(err: SerializableError) -> Self {
+        match err {
+            SerializableError::ConnectionFailed(msg) => DatabaseError::ConnectionFailed(msg),
+            SerializableError::QueryFailed(msg) => DatabaseError::QueryFailed(msg),
+            SerializableError::InvalidData(msg) => DatabaseError::InvalidData(msg),
+            SerializableError::NotFound(msg) => DatabaseError::NotFound(msg),
+            SerializableError::Timeout(msg) => DatabaseError::Timeout(msg),
+            SerializableError::Backend(msg) => DatabaseError::Backend(msg),
+        }
+    }
+}
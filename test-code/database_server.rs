@@ -0,0 +1,207 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use futures::{SinkExt, StreamExt};
+use tokio::net::{UnixListener, UnixStream};
+use tokio_util::codec::{Framed, LengthDelimitedCodec};
+
+use crate::database_protocol::{Request, Response, DEFAULT_SOCKET_PATH};
+use crate::database::{DatabaseConfig, DatabaseManager};
+
+/// Long-lived daemon that owns the real `DatabaseManager` and its
+/// connection pool, so many short-lived `DatabaseClient` invocations share
+/// one warm pool instead of each spinning up its own, and so the process
+/// holding database credentials is never the same one a client runs as.
+pub struct DatabaseServer {
+    manager: Arc<DatabaseManager>,
+    socket_path: String,
+}
+
+impl DatabaseServer {
+    pub fn new(config: DatabaseConfig) -> Self {
+        Self::with_socket_path(config, DEFAULT_SOCKET_PATH)
+    }
+
+    pub fn with_socket_path(config: DatabaseConfig, socket_path: impl Into<String>) -> Self {
+        DatabaseServer {
+            manager: Arc::new(DatabaseManager::new(config)),
+            socket_path: socket_path.into(),
+        }
+    }
+
+    /// Bind the Unix domain socket and serve client connections until the
+    /// process is terminated.
+    pub async fn run(&self) -> std::io::Result<()> {
+        let listener = self.bind_listener()?;
+        self.serve(listener).await
+    }
+
+    /// Serve client connections on an already-bound listener. Split out from
+    /// `run` so code in this module (such as the tests) that needs the socket bound synchronously
+    /// before anything else proceeds can call `bind_listener` themselves and
+    /// hand the result here, instead of racing `run`'s internal bind.
+    async fn serve(&self, listener: UnixListener) -> std::io::Result<()> {
+        #[cfg(feature = "systemd")]
+        Self::notify_ready();
+
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let manager = Arc::clone(&self.manager);
+            tokio::spawn(async move {
+                if let Err(err) = Self::handle_connection(stream, manager).await {
+                    eprintln!("[database-server] connection error: {err}");
+                }
+            });
+        }
+    }
+
+    /// Bind `socket_path`, or adopt the socket-activated listener systemd
+    /// already bound for us (fd 3) when the `systemd` feature is enabled and
+    /// `LISTEN_FDS` is `1`.
+    fn bind_listener(&self) -> std::io::Result<UnixListener> {
+        #[cfg(feature = "systemd")]
+        if let Some(listener) = Self::socket_activated_listener()? {
+            return Ok(listener);
+        }
+
+        if Path::new(&self.socket_path).exists() {
+            std::fs::remove_file(&self.socket_path)?;
+        }
+        UnixListener::bind(&self.socket_path)
+    }
+
+    #[cfg(feature = "systemd")]
+    fn socket_activated_listener() -> std::io::Result<Option<UnixListener>> {
+        use std::os::fd::FromRawFd;
+
+        if std::env::var("LISTEN_FDS").ok().as_deref() != Some("1") {
+            return Ok(None);
+        }
+        // systemd hands us the listening socket pre-bound on fd 3.
+        let std_listener = unsafe { std::os::unix::net::UnixListener::from_raw_fd(3) };
+        std_listener.set_nonblocking(true)?;
+        Ok(Some(UnixListener::from_std(std_listener)?))
+    }
+
+    #[cfg(feature = "systemd")]
+    fn notify_ready() {
+        let _ = sd_notify::notify(true, &[sd_notify::NotifyState::Ready]);
+        tokio::spawn(async {
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(15)).await;
+                let _ = sd_notify::notify(false, &[sd_notify::NotifyState::Watchdog]);
+            }
+        });
+    }
+
+    async fn handle_connection(
+        stream: UnixStream,
+        manager: Arc<DatabaseManager>,
+    ) -> std::io::Result<()> {
+        let mut framed = Framed::new(stream, LengthDelimitedCodec::new());
+
+        while let Some(frame) = framed.next().await {
+            let request: Request = match serde_json::from_slice(&frame?) {
+                Ok(request) => request,
+                Err(err) => {
+                    eprintln!("[database-server] malformed request: {err}");
+                    continue;
+                }
+            };
+
+            let response = Self::dispatch(&manager, request).await;
+            let encoded = serde_json::to_vec(&response).expect("Response is always serializable");
+            framed.send(encoded.into()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn dispatch(manager: &DatabaseManager, request: Request) -> Response {
+        match request {
+            Request::ExecuteQuery { query, params } => {
+                let params: Vec<&str> = params.iter().map(String::as_str).collect();
+                match manager.execute_query(&query, &params).await {
+                    Ok(records) => Response::Records(records),
+                    Err(err) => Response::Err(err.into()),
+                }
+            }
+            Request::Insert { table, data } => match manager.insert_record(&table, data).await {
+                Ok(id) => Response::RowsAffected(id),
+                Err(err) => Response::Err(err.into()),
+            },
+            Request::Update { table, id, data } => {
+                match manager.update_record(&table, id, data).await {
+                    Ok(ok) => Response::Ok(ok),
+                    Err(err) => Response::Err(err.into()),
+                }
+            }
+            Request::Delete { table, id } => match manager.delete_record(&table, id).await {
+                Ok(ok) => Response::Ok(ok),
+                Err(err) => Response::Err(err.into()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::DatabaseConfig;
+    use crate::database_client::DatabaseClient;
+
+    fn unique_socket_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("gemini-db-test-{name}-{}.sock", std::process::id()))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[tokio::test]
+    async fn test_client_server_round_trip() {
+        let socket_path = unique_socket_path("round-trip");
+        let config = DatabaseConfig {
+            host: "localhost".to_string(),
+            port: 5432,
+            username: "test_user".to_string(),
+            password: "test_pass".to_string(),
+            database: "test_db".to_string(),
+        };
+
+        let server = DatabaseServer::with_socket_path(config, socket_path.clone());
+        let listener = server
+            .bind_listener()
+            .expect("binding the test socket should succeed");
+        tokio::spawn(async move {
+            let _ = server.serve(listener).await;
+        });
+
+        let mut client = DatabaseClient::connect_to(&socket_path)
+            .await
+            .expect("client should connect to the daemon's socket");
+        let records = client
+            .execute_query("SELECT * FROM users", &[])
+            .await
+            .expect("query should round-trip through the daemon");
+        assert_eq!(records.len(), 1);
+
+        let id = client
+            .insert_record("users", std::collections::HashMap::new())
+            .await
+            .expect("insert should round-trip through the daemon");
+        assert!(
+            client
+                .update_record("users", id, std::collections::HashMap::new())
+                .await
+                .expect("update should round-trip through the daemon")
+        );
+        assert!(
+            client
+                .delete_record("users", id)
+                .await
+                .expect("delete should round-trip through the daemon")
+        );
+
+        let _ = std::fs::remove_file(&socket_path);
+    }
+}
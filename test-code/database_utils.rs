@@ -1,244 +0,0 @@
-use std::collections::HashMap;
-use std::error::Error;
-use std::fmt;
-
-/// Database connection configuration
-#[derive(Debug, Clone)]
-pub struct DatabaseConfig {
-    pub host: String,
-    pub port: u16,
-    pub username: String,
-    pub password: String,
-    pub database: String,
-}
-
-/// Custom error type for database operations
-#[derive(Debug)]
-pub enum DatabaseError {
-    ConnectionFailed(String),
-    QueryFailed(String),
-    InvalidData(String),
-    NotFound(String),
-}
-
-impl fmt::Display for DatabaseError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            DatabaseError::ConnectionFailed(msg) => write!(f, "Connection failed: {}", msg),
-            DatabaseError::QueryFailed(msg) => write!(f, "Query failed: {}", msg),
-            DatabaseError::InvalidData(msg) => write!(f, "Invalid data: {}", msg),
-            DatabaseError::NotFound(msg) => write!(f, "Not found: {}", msg),
-        }
-    }
-}
-
-impl Error for DatabaseError {}
-
-/// Represents a database record
-#[derive(Debug, Clone)]
-pub struct Record {
-    pub id: u64,
-    pub data: HashMap<String, String>,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    pub updated_at: chrono::DateTime<chrono::Utc>,
-}
-
-/// Database connection manager
-pub struct DatabaseManager {
-    config: DatabaseConfig,
-    connection_pool: Vec<DatabaseConnection>,
-    max_connections: usize,
-}
-
-impl DatabaseManager {
-    pub fn new(config: DatabaseConfig) -> Self {
-        DatabaseManager {
-            config,
-            connection_pool: Vec::new(),
-            max_connections: 10,
-        }
-    }
-
-    /// Establish a new database connection
-    pub async fn connect(&mut self) -> Result<DatabaseConnection, DatabaseError> {
-        if self.connection_pool.len() >= self.max_connections {
-            return Err(DatabaseError::ConnectionFailed(
-                "Maximum connections reached".to_string(),
-            ));
-        }
-
-        let connection = DatabaseConnection::new(&self.config).await?;
-        self.connection_pool.push(connection.clone());
-        Ok(connection)
-    }
-
-    /// Execute a query and return results
-    pub async fn execute_query(
-        &self,
-        query: &str,
-        params: &[&str],
-    ) -> Result<Vec<Record>, DatabaseError> {
-        let connection = self.get_available_connection()?;
-        connection.execute_query(query, params).await
-    }
-
-    /// Insert a new record
-    pub async fn insert_record(
-        &self,
-        table: &str,
-        data: HashMap<String, String>,
-    ) -> Result<u64, DatabaseError> {
-        let connection = self.get_available_connection()?;
-        connection.insert_record(table, data).await
-    }
-
-    /// Update an existing record
-    pub async fn update_record(
-        &self,
-        table: &str,
-        id: u64,
-        data: HashMap<String, String>,
-    ) -> Result<bool, DatabaseError> {
-        let connection = self.get_available_connection()?;
-        connection.update_record(table, id, data).await
-    }
-
-    /// Delete a record
-    pub async fn delete_record(&self, table: &str, id: u64) -> Result<bool, DatabaseError> {
-        let connection = self.get_available_connection()?;
-        connection.delete_record(table, id).await
-    }
-
-    /// Get an available connection from the pool
-    fn get_available_connection(&self) -> Result<&DatabaseConnection, DatabaseError> {
-        self.connection_pool
-            .first()
-            .ok_or_else(|| DatabaseError::ConnectionFailed("No available connections".to_string()))
-    }
-}
-
-/// Individual database connection
-#[derive(Debug, Clone)]
-pub struct DatabaseConnection {
-    pub id: String,
-    pub is_active: bool,
-    pub last_used: chrono::DateTime<chrono::Utc>,
-}
-
-impl DatabaseConnection {
-    pub async fn new(config: &DatabaseConfig) -> Result<Self, DatabaseError> {
-        // Simulate connection establishment
-        let connection = DatabaseConnection {
-            id: format!("conn_{}", uuid::Uuid::new_v4()),
-            is_active: true,
-            last_used: chrono::Utc::now(),
-        };
-
-        // Simulate connection test
-        if !Self::test_connection(config).await {
-            return Err(DatabaseError::ConnectionFailed(
-                "Failed to establish connection".to_string(),
-            ));
-        }
-
-        Ok(connection)
-    }
-
-    async fn test_connection(config: &DatabaseConfig) -> bool {
-        // Simulate connection test
-        config.host.len() > 0 && config.port > 0
-    }
-
-    pub async fn execute_query(
-        &self,
-        query: &str,
-        params: &[&str],
-    ) -> Result<Vec<Record>, DatabaseError> {
-        // Simulate query execution
-        if query.is_empty() {
-            return Err(DatabaseError::QueryFailed("Empty query".to_string()));
-        }
-
-        // Return mock data
-        let mut record = Record {
-            id: 1,
-            data: HashMap::new(),
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-        };
-        record.data.insert("query".to_string(), query.to_string());
-        record.data.insert("params_count".to_string(), params.len().to_string());
-
-        Ok(vec![record])
-    }
-
-    pub async fn insert_record(
-        &self,
-        table: &str,
-        data: HashMap<String, String>,
-    ) -> Result<u64, DatabaseError> {
-        // Simulate record insertion
-        if table.is_empty() {
-            return Err(DatabaseError::InvalidData("Empty table name".to_string()));
-        }
-
-        Ok(chrono::Utc::now().timestamp() as u64)
-    }
-
-    pub async fn update_record(
-        &self,
-        table: &str,
-        id: u64,
-        data: HashMap<String, String>,
-    ) -> Result<bool, DatabaseError> {
-        // Simulate record update
-        if table.is_empty() || id == 0 {
-            return Err(DatabaseError::InvalidData("Invalid table or ID".to_string()));
-        }
-
-        Ok(true)
-    }
-
-    pub async fn delete_record(&self, table: &str, id: u64) -> Result<bool, DatabaseError> {
-        // Simulate record deletion
-        if table.is_empty() || id == 0 {
-            return Err(DatabaseError::InvalidData("Invalid table or ID".to_string()));
-        }
-
-        Ok(true)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[tokio::test]
-    async fn test_database_connection() {
-        let config = DatabaseConfig {
-            host: "localhost".to_string(),
-            port: 5432,
-            username: "test_user".to_string(),
-            password: "test_pass".to_string(),
-            database: "test_db".to_string(),
-        };
-
-        let connection = DatabaseConnection::new(&config).await;
-        assert!(connection.is_ok());
-    }
-
-    #[tokio::test]
-    async fn test_query_execution() {
-        let config = DatabaseConfig {
-            host: "localhost".to_string(),
-            port: 5432,
-            username: "test_user".to_string(),
-            password: "test_pass".to_string(),
-            database: "test_db".to_string(),
-        };
-
-        let connection = DatabaseConnection::new(&config).await.unwrap();
-        let result = connection.execute_query("SELECT * FROM users", &[]).await;
-        assert!(result.is_ok());
-    }
-}